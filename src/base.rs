@@ -0,0 +1,142 @@
+/*
+ * teleinfo-parser
+ * Copyright (c) 2018, 2019 Nicolas PENINGUY.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::*;
+use frame::*;
+use std::io;
+
+/// All the usefull informations that can be extracted when in the Base tariff option.
+#[derive(Debug)]
+pub struct BaseInfos {
+
+    /// The date and time at which the frame was received.
+    pub date: DateTime<Local>,
+    /// The value of the Base meter, in Wh.
+    pub base: i32,
+    /// The current intensity in A (informative).
+    pub iinst: i32,
+    /// Apparent power, in W (informative).
+    pub papp: i32,
+    /// True if maximum subscribed intensity is exceeded.
+    pub alerte: bool
+}
+
+struct BaseInfosBuilder {
+
+    date: Option<DateTime<Local>>,
+    base: Option<i32>,
+    iinst: Option<i32>,
+    papp: Option<i32>,
+    alerte: bool
+}
+
+macro_rules! get {
+    ($e:expr, $msg:expr) => (match $e { Some(e) => e, None => return Err(TeleinfoError::FrameError($msg.to_string())) })
+}
+
+impl BaseInfos {
+
+    /// Try to read informations from the next frame. Any lowlevel error in the frame
+    /// (e.g. wrong checksum) will be returned as is. Additionnaly, the function will
+    /// ensure that all the expected fields are indeed present. If not, a FrameError will be
+    /// returned.
+    pub fn read<T: io::Read>(mut input: &mut T) -> Result<BaseInfos, TeleinfoError> {
+
+        let frame = Frame::next_frame(&mut input)?;
+
+        return BaseInfos::from(frame);
+    }
+
+    pub(crate) fn from(frame: Frame) -> Result<BaseInfos, TeleinfoError> {
+
+        let mut builder = BaseInfosBuilder::new();
+        let now: DateTime<Local> = Local::now();
+
+        builder.date(now);
+
+        for tag in frame.tags {
+            match tag {
+                Tag::BASE(v) => {
+                    builder.base(v);
+                },
+                Tag::IINST(v) => {
+                    builder.iinst(v);
+                },
+                Tag::PAPP(v) => {
+                    builder.papp(v);
+                },
+                Tag::ADPS(_) => {
+                    builder.alerte(true);
+                },
+                _ => ()
+            };
+        }
+
+        builder.build()
+    }
+}
+
+impl BaseInfosBuilder {
+
+    fn new() -> BaseInfosBuilder {
+        BaseInfosBuilder {
+            date: None,
+            base: None,
+            iinst: None,
+            papp: None,
+            alerte: false
+        }
+    }
+
+    fn date(&mut self, date: DateTime<Local>) -> &mut BaseInfosBuilder {
+        self.date = Some(date);
+        self
+    }
+
+    fn base(&mut self, base: i32) -> &mut BaseInfosBuilder {
+        self.base = Some(base);
+        self
+    }
+
+    fn iinst(&mut self, iinst: i32) -> &mut BaseInfosBuilder {
+        self.iinst = Some(iinst);
+        self
+    }
+
+    fn papp(&mut self, papp: i32) -> &mut BaseInfosBuilder {
+        self.papp = Some(papp);
+        self
+    }
+
+    fn alerte(&mut self, alerte: bool) -> &mut BaseInfosBuilder {
+        self.alerte = alerte;
+        self
+    }
+
+    fn build(self) -> Result<BaseInfos, TeleinfoError> {
+        let infos = BaseInfos {
+            date: get!(self.date, "Missing date"),
+            base: get!(self.base, "Missing base"),
+            iinst: get!(self.iinst, "Missing iinst"),
+            papp: get!(self.papp, "Missing papp"),
+            alerte: self.alerte
+        };
+
+        Ok(infos)
+    }
+}
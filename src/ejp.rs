@@ -0,0 +1,155 @@
+/*
+ * teleinfo-parser
+ * Copyright (c) 2018, 2019 Nicolas PENINGUY.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::*;
+use frame::*;
+use std::io;
+
+/// All the usefull informations that can be extracted when in the EJP tariff option.
+#[derive(Debug)]
+pub struct EjpInfos {
+
+    /// The date and time at which the frame was received.
+    pub date: DateTime<Local>,
+    /// The value of the Heures Normales meter, in Wh.
+    pub hn: i32,
+    /// The value of the Heures de Pointe Mobile meter, in Wh.
+    pub hpm: i32,
+    /// The current intensity in A (informative).
+    pub iinst: i32,
+    /// Apparent power, in W (informative).
+    pub papp: i32,
+    /// True when a peak period is announced to start within the next 30 minutes.
+    pub preavis: bool
+}
+
+struct EjpInfosBuilder {
+
+    date: Option<DateTime<Local>>,
+    hn: Option<i32>,
+    hpm: Option<i32>,
+    iinst: Option<i32>,
+    papp: Option<i32>,
+    preavis: bool
+}
+
+macro_rules! get {
+    ($e:expr, $msg:expr) => (match $e { Some(e) => e, None => return Err(TeleinfoError::FrameError($msg.to_string())) })
+}
+
+impl EjpInfos {
+
+    /// Try to read informations from the next frame. Any lowlevel error in the frame
+    /// (e.g. wrong checksum) will be returned as is. Additionnaly, the function will
+    /// ensure that all the expected fields are indeed present. If not, a FrameError will be
+    /// returned.
+    pub fn read<T: io::Read>(mut input: &mut T) -> Result<EjpInfos, TeleinfoError> {
+
+        let frame = Frame::next_frame(&mut input)?;
+
+        return EjpInfos::from(frame);
+    }
+
+    pub(crate) fn from(frame: Frame) -> Result<EjpInfos, TeleinfoError> {
+
+        let mut builder = EjpInfosBuilder::new();
+        let now: DateTime<Local> = Local::now();
+
+        builder.date(now);
+
+        for tag in frame.tags {
+            match tag {
+                Tag::EJPHN(v) => {
+                    builder.hn(v);
+                },
+                Tag::EJPHPM(v) => {
+                    builder.hpm(v);
+                },
+                Tag::IINST(v) => {
+                    builder.iinst(v);
+                },
+                Tag::PAPP(v) => {
+                    builder.papp(v);
+                },
+                Tag::PEJP(_) => {
+                    builder.preavis(true);
+                },
+                _ => ()
+            };
+        }
+
+        builder.build()
+    }
+}
+
+impl EjpInfosBuilder {
+
+    fn new() -> EjpInfosBuilder {
+        EjpInfosBuilder {
+            date: None,
+            hn: None,
+            hpm: None,
+            iinst: None,
+            papp: None,
+            preavis: false
+        }
+    }
+
+    fn date(&mut self, date: DateTime<Local>) -> &mut EjpInfosBuilder {
+        self.date = Some(date);
+        self
+    }
+
+    fn hn(&mut self, hn: i32) -> &mut EjpInfosBuilder {
+        self.hn = Some(hn);
+        self
+    }
+
+    fn hpm(&mut self, hpm: i32) -> &mut EjpInfosBuilder {
+        self.hpm = Some(hpm);
+        self
+    }
+
+    fn iinst(&mut self, iinst: i32) -> &mut EjpInfosBuilder {
+        self.iinst = Some(iinst);
+        self
+    }
+
+    fn papp(&mut self, papp: i32) -> &mut EjpInfosBuilder {
+        self.papp = Some(papp);
+        self
+    }
+
+    fn preavis(&mut self, preavis: bool) -> &mut EjpInfosBuilder {
+        self.preavis = preavis;
+        self
+    }
+
+    fn build(self) -> Result<EjpInfos, TeleinfoError> {
+        let infos = EjpInfos {
+            date: get!(self.date, "Missing date"),
+            hn: get!(self.hn, "Missing hn"),
+            hpm: get!(self.hpm, "Missing hpm"),
+            iinst: get!(self.iinst, "Missing iinst"),
+            papp: get!(self.papp, "Missing papp"),
+            preavis: self.preavis
+        };
+
+        Ok(infos)
+    }
+}
@@ -16,7 +16,9 @@
  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  */
 
-use ::std::io::{self, Read};
+use ::std::io::{self, BufReader, Read};
+
+use chrono::{DateTime, FixedOffset, TimeZone};
 
 const STX: char = '\u{0002}';
 const ETX: char = '\u{0003}';
@@ -26,9 +28,31 @@ const LF: char = '\u{000A}';
 const CR: char = '\u{000D}';
 
 const SP: char = '\u{0020}';
-// const HT: char = '\u{0009}';
+const HT: char = '\u{0009}';
+
+/// The way a frame is encoded on the line.
+///
+/// Historical meters and Linky meters in mode *historique* separate the
+/// fields of a group with a space, while Linky meters in mode *standard* use a
+/// horizontal tab and compute the group checksum over the separators as well.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrameMode {
+    /// Historique TIC (default for older meters and the Linky factory mode).
+    Historique,
+    /// Standard TIC, emitted by Linky meters once switched to mode standard.
+    Standard
+}
 
-const SEPARATOR: char = SP;
+impl FrameMode {
+
+    /// The character separating the fields of a group in this mode.
+    fn separator(self) -> char {
+        match self {
+            FrameMode::Historique => SP,
+            FrameMode::Standard => HT
+        }
+    }
+}
 
 /// The subscribed tariff option
 #[derive(Debug)]
@@ -36,6 +60,7 @@ pub enum OptionTarifaire {
     Base,
     HC,
     EJP,
+    Tempo,
     UNKNOWN(String)
 }
 
@@ -82,6 +107,65 @@ pub enum Tag {
     HHPHC(char),
     /// Mot d'état du compteur
     MOTDETAT(String),
+
+    /// Index option EJP, Heures Normales
+    EJPHN(i32),
+    /// Index option EJP, Heures de Pointe Mobile
+    EJPHPM(i32),
+    /// Préavis Heures EJP (début de pointe dans 30 minutes)
+    PEJP(i32),
+    /// Index option Tempo, Heures Creuses Jours Bleus
+    BBRHCJB(i32),
+    /// Index option Tempo, Heures Pleines Jours Bleus
+    BBRHPJB(i32),
+    /// Index option Tempo, Heures Creuses Jours Blancs
+    BBRHCJW(i32),
+    /// Index option Tempo, Heures Pleines Jours Blancs
+    BBRHPJW(i32),
+    /// Index option Tempo, Heures Creuses Jours Rouges
+    BBRHCJR(i32),
+    /// Index option Tempo, Heures Pleines Jours Rouges
+    BBRHPJR(i32),
+    /// Couleur du lendemain (option Tempo)
+    DEMAIN(String),
+
+    /// Adresse secondaire du compteur (mode standard)
+    ADSC(String),
+    /// Version de la TIC (mode standard)
+    VTIC(String),
+    /// Date et heure courante (mode standard, horodatée)
+    DATE(DateTime<FixedOffset>),
+    /// Nom du calendrier tarifaire fournisseur (mode standard)
+    NGTF(String),
+    /// Libellé tarif en cours (mode standard)
+    LTARF(String),
+    /// Énergie active soutirée totale, en Wh (mode standard)
+    EAST(i32),
+    /// Énergie active soutirée fournisseur, index 01 à 10, en Wh (mode standard)
+    EASF(u8, i32),
+    /// Courant efficace phase 1, en A (mode standard)
+    IRMS1(i32),
+    /// Tension efficace phase 1, en V (mode standard)
+    URMS1(i32),
+    /// Puissance apparente de référence, en kVA (mode standard)
+    PREF(i32),
+    /// Puissance apparente de coupure, en kVA (mode standard)
+    PCOUP(i32),
+    /// Puissance apparente soutirée instantanée, en VA (mode standard)
+    SINSTS(i32),
+    /// Puissance apparente soutirée maximale du jour, en VA (mode standard, horodatée)
+    SMAXSN(DateTime<FixedOffset>, i32),
+    /// Tension moyenne phase 1, en V (mode standard, horodatée)
+    UMOY1(DateTime<FixedOffset>, i32),
+    /// Registre de statuts (mode standard)
+    STGE(String),
+    /// État des relais (mode standard)
+    RELAIS(i32),
+    /// Numéro de l'index tarifaire en cours (mode standard)
+    NTARF(i32),
+    /// Numéro du jour en cours dans le calendrier fournisseur (mode standard)
+    NJOURF(i32),
+
     /// Groupe d'information inconnu ou non géré
     UNKNOWN(String, String)
 }
@@ -99,11 +183,122 @@ impl Frame {
         }
     }
 
-    pub fn next_frame<T: Read>(mut input: &mut T) -> Result<Frame, TeleinfoError> {
+    pub fn next_frame<T: Read>(input: &mut T) -> Result<Frame, TeleinfoError> {
+
+        // Read straight from the caller's reader, one byte at a time, so that
+        // nothing past this frame's terminator is consumed and the next call
+        // starts where this one stopped. `FrameReader` keeps a `BufReader`
+        // across frames for buffered reading.
+        let mut buf = Vec::new();
+        return read_buffered_frame(input, &mut buf);
+    }
+}
+
+/// Fill `buf` from `input` with one frame and parse it, reusing the caller's
+/// buffer across calls so the hot path avoids reallocating on every frame.
+fn read_buffered_frame<T: Read>(input: &mut T, buf: &mut Vec<u8>) -> Result<Frame, TeleinfoError> {
+
+    buf.clear();
+    fill_frame(input, buf)?;
+
+    let mut decoder = Decoder::new(buf);
+
+    skip_to(&mut decoder, STX)?;
+
+    return read_frame(&mut decoder);
+}
+
+/// A cursor over a byte buffer holding (part of) a frame.
+///
+/// The parser works against this view rather than reading one byte at a time
+/// off the serial port, which lets it look ahead with [`Decoder::peek`]. The
+/// cursor only moves forward.
+struct Decoder<'a> {
+    buf: &'a [u8],
+    offset: usize
+}
+
+impl<'a> Decoder<'a> {
+
+    fn new(buf: &'a [u8]) -> Decoder<'a> {
+        Decoder {
+            buf,
+            offset: 0
+        }
+    }
+
+    /// Number of bytes left to decode.
+    fn remaining(&self) -> usize {
+        self.buf.len() - self.offset
+    }
+
+    /// Next character without advancing the cursor, or `None` at the end.
+    fn peek(&self) -> Option<char> {
+        if self.offset < self.buf.len() {
+            Some(self.buf[self.offset] as char)
+        } else {
+            None
+        }
+    }
+
+    /// Decode one character, advancing the cursor. A spent buffer is
+    /// `EndOfFile` and an `EOT` byte is `EndOfTransmission`.
+    fn decode_char(&mut self) -> Result<char, TeleinfoError> {
+
+        if self.offset >= self.buf.len() {
+            return Err(TeleinfoError::EndOfFile);
+        }
+
+        let c = self.buf[self.offset] as char;
+        self.offset += 1;
+
+        if c == EOT {
+            return Err(TeleinfoError::EndOfTransmission);
+        }
+
+        Ok(c)
+    }
+
+    /// Decode characters up to, and consuming, the next `sep`.
+    fn decode_until(&mut self, sep: char) -> Result<String, TeleinfoError> {
+
+        let mut result = String::new();
+
+        loop {
+            let c = self.decode_char()?;
+
+            if c == sep {
+                break;
+            }
+
+            result.push(c);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Read bytes from the serial port into `buf` until a whole frame is buffered,
+/// i.e. up to and including the terminating `ETX` (or an `EOT` marking an
+/// interrupted transmission, left in the buffer for the decoder to report).
+fn fill_frame<T: Read>(input: &mut T, buf: &mut Vec<u8>) -> Result<(), TeleinfoError> {
+
+    let mut byte = [0u8; 1];
+
+    loop {
+        let count = input.read(&mut byte)?;
+
+        if count == 0 {
+            return Err(TeleinfoError::EndOfFile);
+        }
+
+        buf.push(byte[0]);
 
-        skip_to(&mut input, STX)?;
+        let c = byte[0] as char;
 
-        return read_frame(&mut input);
+        if c == ETX || c == EOT {
+            return Ok(());
+        }
     }
 }
 
@@ -123,6 +318,22 @@ pub enum TeleinfoError {
     ChecksumError
 }
 
+impl TeleinfoError {
+
+    /// Whether reading another frame may succeed after this error. Checksum
+    /// mismatches, malformed tags and interrupted transmissions are transient
+    /// and usually caused by line noise; end of file and I/O errors are fatal.
+    pub fn is_recoverable(&self) -> bool {
+        match *self {
+            TeleinfoError::EndOfTransmission
+            | TeleinfoError::FrameError(_)
+            | TeleinfoError::ChecksumError => true,
+            TeleinfoError::EndOfFile
+            | TeleinfoError::IoError(_) => false
+        }
+    }
+}
+
 impl From<io::Error> for TeleinfoError {
 
     fn from(err: io::Error) -> TeleinfoError {
@@ -130,12 +341,90 @@ impl From<io::Error> for TeleinfoError {
     }
 }
 
-fn read_frame<T: Read>(mut input: &mut T) -> Result<Frame, TeleinfoError> {
+/// How a [`FrameReader`] treats the transient errors it recovers from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecoveryMode {
+    /// Silently drop malformed frames and resynchronize on the next frame.
+    Skip,
+    /// Yield each transient error once, then resynchronize on the next call.
+    Report
+}
+
+/// An [`Iterator`] over the frames of a reader that recovers from the line
+/// noise these meters routinely produce.
+///
+/// A recoverable error (bad checksum, malformed tag, interrupted transmission)
+/// makes the reader skip forward to the next frame instead of giving up;
+/// depending on the [`RecoveryMode`] the error is either swallowed or yielded
+/// once before recovery. A fatal error (end of file, I/O error) ends the
+/// iteration.
+pub struct FrameReader<T: Read> {
+    input: BufReader<T>,
+    buf: Vec<u8>,
+    recovery: RecoveryMode,
+    done: bool
+}
+
+impl<T: Read> FrameReader<T> {
+
+    /// A reader that silently skips malformed frames ([`RecoveryMode::Skip`]).
+    pub fn new(input: T) -> FrameReader<T> {
+        FrameReader::with_recovery(input, RecoveryMode::Skip)
+    }
+
+    /// A reader using the given [`RecoveryMode`].
+    ///
+    /// The reader is wrapped in a [`BufReader`] so that filling a frame no
+    /// longer issues one `read()` syscall per byte on the serial port.
+    pub fn with_recovery(input: T, recovery: RecoveryMode) -> FrameReader<T> {
+        FrameReader {
+            input: BufReader::new(input),
+            buf: Vec::new(),
+            recovery,
+            done: false
+        }
+    }
+}
+
+impl<T: Read> Iterator for FrameReader<T> {
+
+    type Item = Result<Frame, TeleinfoError>;
+
+    fn next(&mut self) -> Option<Result<Frame, TeleinfoError>> {
+
+        if self.done {
+            return None;
+        }
+
+        loop {
+            match read_buffered_frame(&mut self.input, &mut self.buf) {
+                Ok(frame) => return Some(Ok(frame)),
+                Err(e) => {
+                    if !e.is_recoverable() {
+                        self.done = true;
+                        return None;
+                    }
+
+                    // The next `read_buffered_frame` call reads from just past this
+                    // frame's terminator, so it naturally resynchronizes on
+                    // the following STX.
+                    match self.recovery {
+                        RecoveryMode::Skip => continue,
+                        RecoveryMode::Report => return Some(Err(e))
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn read_frame(decoder: &mut Decoder) -> Result<Frame, TeleinfoError> {
 
     let mut frame = Frame::new();
+    let mut mode: Option<FrameMode> = None;
 
     loop {
-        let c = read_char(&mut input)?;
+        let c = decoder.decode_char()?;
 
         if c == ETX {
             return Ok(frame);
@@ -145,40 +434,134 @@ fn read_frame<T: Read>(mut input: &mut T) -> Result<Frame, TeleinfoError> {
             return Err(TeleinfoError::FrameError(format!("Expected LF but found {}", c)));
         }
 
-        let lbl = read_to_sep(&mut input)?;
-        let val = read_to_sep(&mut input)?;
-        let c = read_char(&mut input)?;
-
-        if c != checksum(&lbl, &val) {
+        // The first separator seen after STX tells the two modes apart: a
+        // space means historique, a tab means standard.
+        let (lbl, m) = match mode {
+            Some(m) => (read_to_sep(decoder, m.separator())?, m),
+            None => {
+                let (lbl, sep) = read_to_any_sep(decoder)?;
+                let m = if sep == HT { FrameMode::Standard } else { FrameMode::Historique };
+                mode = Some(m);
+                (lbl, m)
+            }
+        };
+
+        let sep = m.separator();
+
+        // In mode standard, a handful of groups carry a horodate between the
+        // label and the value; it takes part in the checksum and is decoded
+        // into a timestamp by `parse_tag` for the labels it knows.
+        let horodate = if m == FrameMode::Standard && has_horodate(&lbl) {
+            Some(read_to_sep(decoder, sep)?)
+        } else {
+            None
+        };
+
+        let val = read_to_sep(decoder, sep)?;
+        let c = decoder.decode_char()?;
+
+        if c != checksum(m, &lbl, horodate.as_deref(), &val) {
             return Err(TeleinfoError::ChecksumError);
         }
 
-        let tag = parse_tag(&lbl, &val)?;
+        let tag = parse_tag(&lbl, horodate.as_deref(), &val)?;
 
         frame.tags.push(tag);
 
-        expect_char(&mut input, CR)?;
+        expect_char(decoder, CR)?;
     }
 }
 
-fn checksum(lbl: &str, val: &str) -> char {
+/// Labels that carry a horodate field in mode standard.
+fn has_horodate(lbl: &str) -> bool {
+    matches!(lbl,
+        "DATE" | "SMAXSN" | "SMAXSN-1" | "SMAXIN" | "SMAXIN-1"
+        | "CCASN" | "CCASN-1" | "CCAIN" | "CCAIN-1"
+        | "UMOY1" | "UMOY2" | "UMOY3")
+}
+
+fn checksum(mode: FrameMode, lbl: &str, horodate: Option<&str>, val: &str) -> char {
 
+    let sep = mode.separator();
     let mut sum = 0u8;
 
     for c in lbl.chars() {
         sum = sum.wrapping_add(c as u8);
     }
 
-    sum = sum.wrapping_add(SEPARATOR as u8);
+    sum = sum.wrapping_add(sep as u8);
+
+    if let Some(horodate) = horodate {
+        for c in horodate.chars() {
+            sum = sum.wrapping_add(c as u8);
+        }
+        sum = sum.wrapping_add(sep as u8);
+    }
 
     for c in val.chars() {
         sum = sum.wrapping_add(c as u8);
     }
 
+    // Mode standard (method S2) also folds in the separator that precedes the
+    // checksum byte itself.
+    if mode == FrameMode::Standard {
+        sum = sum.wrapping_add(sep as u8);
+    }
+
     ((sum & 0x3F) + 0x20) as char
 }
 
-fn parse_tag(lbl: &str, val: &str) -> Result<Tag, TeleinfoError> {
+fn parse_num(val: &str) -> Result<i32, TeleinfoError> {
+    val.parse::<i32>()
+        .map_err(|_| TeleinfoError::FrameError(format!("Number parse error on {}", val)))
+}
+
+fn parse_u32(val: &str) -> Result<u32, TeleinfoError> {
+    val.parse::<u32>()
+        .map_err(|_| TeleinfoError::FrameError(format!("Number parse error on {}", val)))
+}
+
+/// Decode a mode standard horodate `S AAMMJJHHMMSS` into a timestamp.
+///
+/// The leading letter is a season flag: `E`/`e` is summer (UTC+2), `H`/`h` is
+/// winter (UTC+1); a lowercase letter means the meter clock is not confirmed
+/// but is parsed just the same. The remaining twelve digits are
+/// `YYMMDDhhmmss`, the year being `2000 + YY`.
+pub fn parse_horodate(horodate: &str) -> Result<DateTime<FixedOffset>, TeleinfoError> {
+
+    // The digit slices below assume one byte per character; a non-ASCII byte
+    // decoded off the wire would make them panic on a char boundary, so reject
+    // it up front as a malformed field.
+    if !horodate.is_ascii() {
+        return Err(TeleinfoError::FrameError(format!("Horodate should be ASCII: {}", horodate)));
+    }
+
+    if horodate.len() != 13 {
+        return Err(TeleinfoError::FrameError(format!("Horodate should be 13 chars long: {}", horodate)));
+    }
+
+    let offset = match &horodate[0..1] {
+        "E" | "e" => FixedOffset::east_opt(2 * 3600).unwrap(),
+        "H" | "h" => FixedOffset::east_opt(3600).unwrap(),
+        flag => return Err(TeleinfoError::FrameError(format!("Unknown horodate season flag {}", flag)))
+    };
+
+    let year = 2000 + parse_u32(&horodate[1..3])? as i32;
+    let month = parse_u32(&horodate[3..5])?;
+    let day = parse_u32(&horodate[5..7])?;
+    let hour = parse_u32(&horodate[7..9])?;
+    let min = parse_u32(&horodate[9..11])?;
+    let sec = parse_u32(&horodate[11..13])?;
+
+    offset.with_ymd_and_hms(year, month, day, hour, min, sec).single()
+        .ok_or_else(|| TeleinfoError::FrameError(format!("Invalid horodate {}", horodate)))
+}
+
+fn require_horodate<'a>(horodate: Option<&'a str>, lbl: &str) -> Result<&'a str, TeleinfoError> {
+    horodate.ok_or_else(|| TeleinfoError::FrameError(format!("Missing horodate for {}", lbl)))
+}
+
+fn parse_tag(lbl: &str, horodate: Option<&str>, val: &str) -> Result<Tag, TeleinfoError> {
 
     let tag = match lbl {
 
@@ -189,6 +572,7 @@ fn parse_tag(lbl: &str, val: &str) -> Result<Tag, TeleinfoError> {
                 "Base" => OptionTarifaire::Base,
                 "HC.." => OptionTarifaire::HC,
                 "EJP." => OptionTarifaire::EJP,
+                "BBR(" => OptionTarifaire::Tempo,
                 _ => OptionTarifaire::UNKNOWN(val.to_string())
             })
         },
@@ -260,45 +644,121 @@ fn parse_tag(lbl: &str, val: &str) -> Result<Tag, TeleinfoError> {
 
         "MOTDETAT" => Tag::MOTDETAT(val.to_string()),
 
+        "EJPHN" => Tag::EJPHN(parse_num(val)?),
+
+        "EJPHPM" => Tag::EJPHPM(parse_num(val)?),
+
+        "PEJP" => Tag::PEJP(parse_num(val)?),
+
+        "BBRHCJB" => Tag::BBRHCJB(parse_num(val)?),
+
+        "BBRHPJB" => Tag::BBRHPJB(parse_num(val)?),
+
+        "BBRHCJW" => Tag::BBRHCJW(parse_num(val)?),
+
+        "BBRHPJW" => Tag::BBRHPJW(parse_num(val)?),
+
+        "BBRHCJR" => Tag::BBRHCJR(parse_num(val)?),
+
+        "BBRHPJR" => Tag::BBRHPJR(parse_num(val)?),
+
+        "DEMAIN" => Tag::DEMAIN(val.to_string()),
+
+        "ADSC" => Tag::ADSC(val.to_string()),
+
+        "VTIC" => Tag::VTIC(val.to_string()),
+
+        "DATE" => Tag::DATE(parse_horodate(require_horodate(horodate, lbl)?)?),
+
+        "NGTF" => Tag::NGTF(val.to_string()),
+
+        "LTARF" => Tag::LTARF(val.to_string()),
+
+        "EAST" => Tag::EAST(parse_num(val)?),
+
+        "EASF01" => Tag::EASF(1, parse_num(val)?),
+        "EASF02" => Tag::EASF(2, parse_num(val)?),
+        "EASF03" => Tag::EASF(3, parse_num(val)?),
+        "EASF04" => Tag::EASF(4, parse_num(val)?),
+        "EASF05" => Tag::EASF(5, parse_num(val)?),
+        "EASF06" => Tag::EASF(6, parse_num(val)?),
+        "EASF07" => Tag::EASF(7, parse_num(val)?),
+        "EASF08" => Tag::EASF(8, parse_num(val)?),
+        "EASF09" => Tag::EASF(9, parse_num(val)?),
+        "EASF10" => Tag::EASF(10, parse_num(val)?),
+
+        "IRMS1" => Tag::IRMS1(parse_num(val)?),
+
+        "URMS1" => Tag::URMS1(parse_num(val)?),
+
+        "PREF" => Tag::PREF(parse_num(val)?),
+
+        "PCOUP" => Tag::PCOUP(parse_num(val)?),
+
+        "SINSTS" => Tag::SINSTS(parse_num(val)?),
+
+        "SMAXSN" => Tag::SMAXSN(parse_horodate(require_horodate(horodate, lbl)?)?, parse_num(val)?),
+
+        "UMOY1" => Tag::UMOY1(parse_horodate(require_horodate(horodate, lbl)?)?, parse_num(val)?),
+
+        "STGE" => Tag::STGE(val.to_string()),
+
+        "RELAIS" => Tag::RELAIS(parse_num(val)?),
+
+        "NTARF" => Tag::NTARF(parse_num(val)?),
+
+        "NJOURF" => Tag::NJOURF(parse_num(val)?),
+
         _ => Tag::UNKNOWN(lbl.to_string(), val.to_string())
     };
 
     Ok(tag)
 }
 
-fn skip_to<T: Read>(mut input: &mut T, stop_char: char) -> Result<(), TeleinfoError> {
+fn skip_to(decoder: &mut Decoder, stop_char: char) -> Result<(), TeleinfoError> {
 
-    loop {
-        let c = read_char(&mut input)?;
+    while decoder.remaining() > 0 {
 
-        if c == stop_char {
-            break;
+        if decoder.peek() == Some(stop_char) {
+            decoder.decode_char()?;
+            return Ok(());
         }
+
+        decoder.decode_char()?;
     }
 
-    Ok(())
+    // A buffered segment that resumed mid-frame (e.g. after a spurious ETX
+    // from line noise) may hold no STX at all. That is a recoverable gap, not
+    // the end of the stream: report a FrameError so FrameReader skips on to
+    // the next frame instead of terminating.
+    Err(TeleinfoError::FrameError(format!("No {:#04x} found in buffer", stop_char as u32)))
 }
 
-fn read_to_sep<T: Read>(mut input: &mut T) -> Result<String, TeleinfoError> {
+fn read_to_sep(decoder: &mut Decoder, sep: char) -> Result<String, TeleinfoError> {
+
+    decoder.decode_until(sep)
+}
+
+/// Read until the first field separator (SP or HT) and report which one was
+/// seen, so the caller can tell the historique and standard modes apart.
+fn read_to_any_sep(decoder: &mut Decoder) -> Result<(String, char), TeleinfoError> {
 
     let mut result: String = String::new();
 
     loop {
-        let c = read_char(&mut input)?;
+        let c = decoder.decode_char()?;
 
-        if c ==  SEPARATOR {
-            break;
+        if c == SP || c == HT {
+            return Ok((result, c));
         }
 
         result.push(c);
     }
-
-    Ok(result)
 }
 
-fn expect_char<T: Read>(mut input: &mut T, expected: char) -> Result<(), TeleinfoError> {
+fn expect_char(decoder: &mut Decoder, expected: char) -> Result<(), TeleinfoError> {
 
-    let c = read_char(&mut input)?;
+    let c = decoder.decode_char()?;
 
     if c != expected {
         return Err(TeleinfoError::FrameError(format!("Expected {} but found {}", expected, c)));
@@ -307,92 +767,127 @@ fn expect_char<T: Read>(mut input: &mut T, expected: char) -> Result<(), Teleinf
     Ok(())
 }
 
-fn read_char<T: Read>(input: &mut T) -> Result<char, TeleinfoError> {
-
-    let mut buf = [0u8; 1];
-    let count = input.read(&mut buf)?;
-
-    if count == 0 {
-        return Err(TeleinfoError::EndOfFile);
-    }
-
-    let c = buf[0] as char;
-
-    if c == EOT {
-        return Err(TeleinfoError::EndOfTransmission);
-    }
-
-    return Ok(c);
-}
-
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use chrono::NaiveDate;
     use std::path::PathBuf;
     use std::fs::File;
 
     #[test]
-    fn test_read_char() {
+    fn test_decode_char() {
 
-        let mut v = &[b'x', 4] as &[u8];
+        let mut d = Decoder::new(&[b'x', 4]);
 
-        let c = read_char(&mut v);
+        let c = d.decode_char();
         assert_matches!(c, Ok('x'));
 
-        let c = read_char(&mut v);
+        let c = d.decode_char();
         assert_matches!(c, Err(TeleinfoError::EndOfTransmission));
 
-        let c = read_char(&mut v);
+        let c = d.decode_char();
         assert_matches!(c, Err(TeleinfoError::EndOfFile));
     }
 
+    #[test]
+    fn test_peek_and_remaining() {
+
+        let mut d = Decoder::new(&[b'a', b'b']);
+
+        assert_eq!(d.remaining(), 2);
+        assert_eq!(d.peek(), Some('a'));
+
+        d.decode_char().unwrap();
+
+        assert_eq!(d.remaining(), 1);
+        assert_eq!(d.peek(), Some('b'));
+
+        d.decode_char().unwrap();
+
+        assert_eq!(d.remaining(), 0);
+        assert_eq!(d.peek(), None);
+    }
+
     #[test]
     fn test_expect_char() {
 
-        let mut v = &[b'a', b'b'] as &[u8];
+        let mut d = Decoder::new(&[b'a', b'b']);
 
-        let r = expect_char(&mut v, 'a');
+        let r = expect_char(&mut d, 'a');
         assert_matches!(r, Ok(()));
 
-        let r = expect_char(&mut v, 'a');
+        let r = expect_char(&mut d, 'a');
         assert_matches!(r, Err(TeleinfoError::FrameError(_)));
     }
 
     #[test]
     fn test_read_to_sep() {
-        let mut v = &[b'a', b'b', b'c', SEPARATOR as u8, b'd'] as &[u8];
+        let mut d = Decoder::new(&[b'a', b'b', b'c', SP as u8, b'd']);
 
-        let r = read_to_sep(&mut v);
+        let r = read_to_sep(&mut d, SP);
         assert_eq!(r.unwrap(), "abc");
 
-        let r = read_to_sep(&mut v);
+        let r = read_to_sep(&mut d, SP);
         assert_matches!(r, Err(TeleinfoError::EndOfFile));
     }
 
+    #[test]
+    fn test_read_to_any_sep() {
+        let mut d = Decoder::new(&[b'a', b'b', HT as u8, b'c']);
+
+        let (r, sep) = read_to_any_sep(&mut d).unwrap();
+        assert_eq!(r, "ab");
+        assert_eq!(sep, HT);
+    }
+
     #[test]
     fn test_skip_to() {
-        let mut v = &[b'a', b'b', b'c'] as &[u8];
+        let mut d = Decoder::new(&[b'a', b'b', b'c']);
 
-        let r = skip_to(&mut v, 'b');
+        let r = skip_to(&mut d, 'b');
         assert_matches!(r, Ok(()));
 
-        let c = read_char(&mut v);
+        let c = d.decode_char();
         assert_matches!(c, Ok('c'));
     }
 
     #[test]
     fn test_parse_tag() {
 
-        let t = parse_tag("BASE", "99").unwrap();
+        let t = parse_tag("BASE", None, "99").unwrap();
         assert_matches!(t, Tag::BASE(99));
+
+        let t = parse_tag("EAST", None, "042").unwrap();
+        assert_matches!(t, Tag::EAST(42));
+    }
+
+    #[test]
+    fn test_parse_horodate() {
+
+        let d = parse_horodate("E210714123456").unwrap();
+        assert_eq!(d.offset(), &FixedOffset::east_opt(2 * 3600).unwrap());
+        assert_eq!(d.naive_local(),
+            NaiveDate::from_ymd_opt(2021, 7, 14).unwrap().and_hms_opt(12, 34, 56).unwrap());
+
+        // A lowercase flag (unconfirmed clock) is accepted.
+        let d = parse_horodate("h210101000000").unwrap();
+        assert_eq!(d.offset(), &FixedOffset::east_opt(3600).unwrap());
+
+        assert_matches!(parse_horodate("E2107141234"), Err(TeleinfoError::FrameError(_)));
+        assert_matches!(parse_horodate("X210714123456"), Err(TeleinfoError::FrameError(_)));
     }
 
     #[test]
     fn test_checksum() {
 
-        let s = checksum("PAPP", "00380");
+        let s = checksum(FrameMode::Historique, "PAPP", None, "00380");
         assert_eq!(s, ',');
+
+        // Mode standard (method S2) folds in the field separators: 'A' (65) +
+        // HT (9) + 'B' (66) + HT (9) = 149, (149 & 0x3F) + 0x20 = '5'.
+        let s = checksum(FrameMode::Standard, "A", None, "B");
+        assert_eq!(s, '5');
     }
 
     #[test]
@@ -420,6 +915,103 @@ mod tests {
 
     }
 
+    fn push_std_group(buf: &mut Vec<u8>, lbl: &str, horodate: Option<&str>, val: &str) {
+
+        let sum = checksum(FrameMode::Standard, lbl, horodate, val);
+
+        buf.push(LF as u8);
+        buf.extend_from_slice(lbl.as_bytes());
+        buf.push(HT as u8);
+        if let Some(h) = horodate {
+            buf.extend_from_slice(h.as_bytes());
+            buf.push(HT as u8);
+        }
+        buf.extend_from_slice(val.as_bytes());
+        buf.push(HT as u8);
+        buf.push(sum as u8);
+        buf.push(CR as u8);
+    }
+
+    #[test]
+    fn test_read_standard_frame() {
+
+        let mut buf = Vec::new();
+        buf.push(STX as u8);
+        push_std_group(&mut buf, "ADSC", None, "012345678901");
+        push_std_group(&mut buf, "DATE", Some("E210714123456"), "");
+        push_std_group(&mut buf, "EAST", None, "063891");
+        buf.push(ETX as u8);
+
+        let frame = Frame::next_frame(&mut &buf[..]).unwrap();
+
+        assert_eq!(frame.tags.len(), 3);
+
+        for tag in frame.tags {
+            match tag {
+                Tag::DATE(d) => {
+                    assert_eq!(d.offset(), &FixedOffset::east_opt(2 * 3600).unwrap());
+                    assert_eq!(d.naive_local(),
+                        NaiveDate::from_ymd_opt(2021, 7, 14).unwrap().and_hms_opt(12, 34, 56).unwrap());
+                },
+                Tag::EAST(v) => {
+                    assert_eq!(v, 63891);
+                },
+                _ => ()
+            };
+        }
+    }
+
+    fn push_group(buf: &mut Vec<u8>, lbl: &str, val: &str, valid: bool) {
+
+        let sum = checksum(FrameMode::Historique, lbl, None, val);
+
+        buf.push(LF as u8);
+        buf.extend_from_slice(lbl.as_bytes());
+        buf.push(SP as u8);
+        buf.extend_from_slice(val.as_bytes());
+        buf.push(SP as u8);
+        buf.push(if valid { sum as u8 } else { sum as u8 ^ 0x01 });
+        buf.push(CR as u8);
+    }
+
+    fn push_frame(buf: &mut Vec<u8>, val: &str, valid: bool) {
+
+        buf.push(STX as u8);
+        push_group(buf, "ADCO", val, valid);
+        buf.push(ETX as u8);
+    }
+
+    #[test]
+    fn test_frame_reader_skip() {
+
+        let mut buf = Vec::new();
+        push_frame(&mut buf, "111111111111", true);
+        push_frame(&mut buf, "222222222222", false);
+        push_frame(&mut buf, "333333333333", true);
+
+        let frames: Vec<_> = FrameReader::new(&buf[..]).collect();
+
+        assert_eq!(frames.len(), 2);
+        assert_matches!(frames[0], Ok(_));
+        assert_matches!(frames[1], Ok(_));
+    }
+
+    #[test]
+    fn test_frame_reader_report() {
+
+        let mut buf = Vec::new();
+        push_frame(&mut buf, "111111111111", true);
+        push_frame(&mut buf, "222222222222", false);
+        push_frame(&mut buf, "333333333333", true);
+
+        let frames: Vec<_> = FrameReader::with_recovery(&buf[..], RecoveryMode::Report).collect();
+
+        assert_eq!(frames.len(), 3);
+        assert_matches!(frames[0], Ok(_));
+        assert_matches!(frames[1], Err(TeleinfoError::ChecksumError));
+        assert_matches!(frames[2], Ok(_));
+    }
+
     fn get_test_file_reader(file_name: &str) -> std::io::Result<File> {
 
         let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -68,7 +68,7 @@ impl HcInfos {
         return HcInfos::from(frame);
     }
 
-    fn from(frame: Frame) -> Result<HcInfos, TeleinfoError> {
+    pub(crate) fn from(frame: Frame) -> Result<HcInfos, TeleinfoError> {
 
         let mut builder = HcInfosBuilder::new();
         let now: DateTime<Local> = Local::now();
@@ -81,7 +81,7 @@ impl HcInfos {
                     builder.periode(match p {
                         PeriodeTarifaire::HP => "HP",
                         PeriodeTarifaire::HC => "HC",
-                        _ => panic!("PeriodeTarifaire does not match HC")
+                        _ => return Err(TeleinfoError::FrameError("PeriodeTarifaire does not match HC".to_string()))
                     });
                 },
                 Tag::HCHC(v) => {
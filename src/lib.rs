@@ -25,5 +25,17 @@ extern crate matches;
 /// Low level Teleinfo frame parsing.
 pub mod frame;
 
+/// Extracting information for the Base OptionTarifaire
+pub mod base;
+
 /// Extracting information for the HC OptionTarifaire
 pub mod hc;
+
+/// Extracting information for the EJP OptionTarifaire
+pub mod ejp;
+
+/// Extracting information for the Tempo OptionTarifaire
+pub mod tempo;
+
+/// Auto-dispatching high-level API keyed on the tariff option.
+pub mod meter;
@@ -0,0 +1,96 @@
+/*
+ * teleinfo-parser
+ * Copyright (c) 2018, 2019 Nicolas PENINGUY.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use base::BaseInfos;
+use ejp::EjpInfos;
+use frame::*;
+use hc::HcInfos;
+use std::io;
+use tempo::TempoInfos;
+
+/// The informations of a frame, extracted for whichever tariff option the meter
+/// announced in its OPTARIF group.
+///
+/// This spares the caller from knowing the subscription in advance: it reads a
+/// single frame and routes it to the matching builder, degrading to the raw
+/// [`Frame`] when the option is not one it handles.
+#[derive(Debug)]
+pub enum MeterInfos {
+    Base(BaseInfos),
+    Hc(HcInfos),
+    Ejp(EjpInfos),
+    Tempo(TempoInfos),
+    Unknown(Frame)
+}
+
+impl MeterInfos {
+
+    /// Read the next frame and extract its informations according to its OPTARIF
+    /// group. Any lowlevel error in the frame (e.g. wrong checksum) is returned
+    /// as is; a frame whose option is unknown is handed back as
+    /// [`MeterInfos::Unknown`].
+    pub fn read<T: io::Read>(mut input: &mut T) -> Result<MeterInfos, TeleinfoError> {
+
+        let frame = Frame::next_frame(&mut input)?;
+
+        return MeterInfos::from(frame);
+    }
+
+    fn from(frame: Frame) -> Result<MeterInfos, TeleinfoError> {
+
+        // Decide the option before consuming the frame, so the borrow needed to
+        // inspect it is released before it is moved into a builder.
+        let option = option_of(&frame);
+
+        let infos = match option {
+            Some(Tarif::Base) => MeterInfos::Base(BaseInfos::from(frame)?),
+            Some(Tarif::Hc) => MeterInfos::Hc(HcInfos::from(frame)?),
+            Some(Tarif::Ejp) => MeterInfos::Ejp(EjpInfos::from(frame)?),
+            Some(Tarif::Tempo) => MeterInfos::Tempo(TempoInfos::from(frame)?),
+            None => MeterInfos::Unknown(frame)
+        };
+
+        Ok(infos)
+    }
+}
+
+/// The tariff options `MeterInfos` knows how to route, derived from a frame's
+/// OPTARIF group without borrowing it past the decision.
+enum Tarif {
+    Base,
+    Hc,
+    Ejp,
+    Tempo
+}
+
+fn option_of(frame: &Frame) -> Option<Tarif> {
+
+    for tag in &frame.tags {
+        if let Tag::OPTARIF(option) = tag {
+            return match *option {
+                OptionTarifaire::Base => Some(Tarif::Base),
+                OptionTarifaire::HC => Some(Tarif::Hc),
+                OptionTarifaire::EJP => Some(Tarif::Ejp),
+                OptionTarifaire::Tempo => Some(Tarif::Tempo),
+                OptionTarifaire::UNKNOWN(_) => None
+            };
+        }
+    }
+
+    None
+}
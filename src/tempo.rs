@@ -0,0 +1,218 @@
+/*
+ * teleinfo-parser
+ * Copyright (c) 2018, 2019 Nicolas PENINGUY.
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use chrono::*;
+use frame::*;
+use std::io;
+
+/// All the usefull informations that can be extracted when in the Tempo tariff option.
+#[derive(Debug)]
+pub struct TempoInfos {
+
+    /// The date and time at which the frame was received.
+    pub date: DateTime<Local>,
+    /// The value of the Heures Creuses Jours Bleus meter, in Wh.
+    pub hcjb: i32,
+    /// The value of the Heures Pleines Jours Bleus meter, in Wh.
+    pub hpjb: i32,
+    /// The value of the Heures Creuses Jours Blancs meter, in Wh.
+    pub hcjw: i32,
+    /// The value of the Heures Pleines Jours Blancs meter, in Wh.
+    pub hpjw: i32,
+    /// The value of the Heures Creuses Jours Rouges meter, in Wh.
+    pub hcjr: i32,
+    /// The value of the Heures Pleines Jours Rouges meter, in Wh.
+    pub hpjr: i32,
+    /// Today's color (BLEU, BLANC or ROUGE), deduced from the current period;
+    /// INCONNU when the period carries no color.
+    pub couleur: String,
+    /// Tomorrow's color (BLEU, BLANC or ROUGE), INCONNU while undecided.
+    pub demain: String
+}
+
+struct TempoInfosBuilder {
+
+    date: Option<DateTime<Local>>,
+    hcjb: Option<i32>,
+    hpjb: Option<i32>,
+    hcjw: Option<i32>,
+    hpjw: Option<i32>,
+    hcjr: Option<i32>,
+    hpjr: Option<i32>,
+    couleur: Option<String>,
+    demain: Option<String>
+}
+
+macro_rules! get {
+    ($e:expr, $msg:expr) => (match $e { Some(e) => e, None => return Err(TeleinfoError::FrameError($msg.to_string())) })
+}
+
+/// The Tempo day color carried, concatenated with the period, by PTEC (e.g. `HPJB`).
+fn couleur_periode(periode: &str) -> &'static str {
+    if periode.ends_with("JB") {
+        "BLEU"
+    } else if periode.ends_with("JW") {
+        "BLANC"
+    } else if periode.ends_with("JR") {
+        "ROUGE"
+    } else {
+        "INCONNU"
+    }
+}
+
+/// The Tempo color announced for the next day by DEMAIN (e.g. `BLEU`, `----`).
+fn couleur_demain(demain: &str) -> &'static str {
+    match demain {
+        "BLEU" => "BLEU",
+        "BLAN" => "BLANC",
+        "ROUG" => "ROUGE",
+        _ => "INCONNU"
+    }
+}
+
+impl TempoInfos {
+
+    /// Try to read informations from the next frame. Any lowlevel error in the frame
+    /// (e.g. wrong checksum) will be returned as is. Additionnaly, the function will
+    /// ensure that all the expected fields are indeed present. If not, a FrameError will be
+    /// returned.
+    pub fn read<T: io::Read>(mut input: &mut T) -> Result<TempoInfos, TeleinfoError> {
+
+        let frame = Frame::next_frame(&mut input)?;
+
+        return TempoInfos::from(frame);
+    }
+
+    pub(crate) fn from(frame: Frame) -> Result<TempoInfos, TeleinfoError> {
+
+        let mut builder = TempoInfosBuilder::new();
+        let now: DateTime<Local> = Local::now();
+
+        builder.date(now);
+
+        for tag in frame.tags {
+            match tag {
+                Tag::BBRHCJB(v) => {
+                    builder.hcjb(v);
+                },
+                Tag::BBRHPJB(v) => {
+                    builder.hpjb(v);
+                },
+                Tag::BBRHCJW(v) => {
+                    builder.hcjw(v);
+                },
+                Tag::BBRHPJW(v) => {
+                    builder.hpjw(v);
+                },
+                Tag::BBRHCJR(v) => {
+                    builder.hcjr(v);
+                },
+                Tag::BBRHPJR(v) => {
+                    builder.hpjr(v);
+                },
+                Tag::PTEC(PeriodeTarifaire::UNKNOWN(p)) => {
+                    builder.couleur(couleur_periode(&p));
+                },
+                Tag::DEMAIN(d) => {
+                    builder.demain(couleur_demain(&d));
+                },
+                _ => ()
+            };
+        }
+
+        builder.build()
+    }
+}
+
+impl TempoInfosBuilder {
+
+    fn new() -> TempoInfosBuilder {
+        TempoInfosBuilder {
+            date: None,
+            hcjb: None,
+            hpjb: None,
+            hcjw: None,
+            hpjw: None,
+            hcjr: None,
+            hpjr: None,
+            couleur: None,
+            demain: None
+        }
+    }
+
+    fn date(&mut self, date: DateTime<Local>) -> &mut TempoInfosBuilder {
+        self.date = Some(date);
+        self
+    }
+
+    fn hcjb(&mut self, hcjb: i32) -> &mut TempoInfosBuilder {
+        self.hcjb = Some(hcjb);
+        self
+    }
+
+    fn hpjb(&mut self, hpjb: i32) -> &mut TempoInfosBuilder {
+        self.hpjb = Some(hpjb);
+        self
+    }
+
+    fn hcjw(&mut self, hcjw: i32) -> &mut TempoInfosBuilder {
+        self.hcjw = Some(hcjw);
+        self
+    }
+
+    fn hpjw(&mut self, hpjw: i32) -> &mut TempoInfosBuilder {
+        self.hpjw = Some(hpjw);
+        self
+    }
+
+    fn hcjr(&mut self, hcjr: i32) -> &mut TempoInfosBuilder {
+        self.hcjr = Some(hcjr);
+        self
+    }
+
+    fn hpjr(&mut self, hpjr: i32) -> &mut TempoInfosBuilder {
+        self.hpjr = Some(hpjr);
+        self
+    }
+
+    fn couleur(&mut self, couleur: &str) -> &mut TempoInfosBuilder {
+        self.couleur = Some(couleur.to_string());
+        self
+    }
+
+    fn demain(&mut self, demain: &str) -> &mut TempoInfosBuilder {
+        self.demain = Some(demain.to_string());
+        self
+    }
+
+    fn build(self) -> Result<TempoInfos, TeleinfoError> {
+        let infos = TempoInfos {
+            date: get!(self.date, "Missing date"),
+            hcjb: get!(self.hcjb, "Missing hcjb"),
+            hpjb: get!(self.hpjb, "Missing hpjb"),
+            hcjw: get!(self.hcjw, "Missing hcjw"),
+            hpjw: get!(self.hpjw, "Missing hpjw"),
+            hcjr: get!(self.hcjr, "Missing hcjr"),
+            hpjr: get!(self.hpjr, "Missing hpjr"),
+            couleur: get!(self.couleur, "Missing couleur"),
+            demain: get!(self.demain, "Missing demain")
+        };
+
+        Ok(infos)
+    }
+}